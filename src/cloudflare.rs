@@ -0,0 +1,91 @@
+use crate::config::Config;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// The zone and account IDs the Cloudflare API resolved for a zone name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneInfo {
+	pub zone_id: String,
+	pub account_id: String,
+}
+
+type Cache = HashMap<String, ZoneInfo>;
+
+/// Resolve a zone name to its zone/account IDs via the Cloudflare API,
+/// using `CLOUDFLARE_API_TOKEN`. Returns `None` (rather than erroring) when
+/// no token is set or the lookup fails, so callers can fall back to the
+/// redirect-based URLs.
+pub fn resolve(zone: &str) -> Option<ZoneInfo> {
+	let token = std::env::var("CLOUDFLARE_API_TOKEN").ok()?;
+
+	let mut cache = load_cache().unwrap_or_default();
+	if let Some(info) = cache.get(zone) {
+		return Some(info.clone());
+	}
+
+	let info = fetch(&token, zone)?;
+	cache.insert(zone.to_string(), info.clone());
+	save_cache(&cache);
+
+	Some(info)
+}
+
+/// Best-effort account ID lookup for account-scoped commands, which have
+/// no zone of their own to resolve against the API. Tries the configured
+/// default zone first, then falls back to the cache, but only when every
+/// cached zone agrees on the same account — with several accounts cached
+/// (e.g. personal + client work) guessing one would be worse than the
+/// harmless `:account` placeholder.
+pub fn resolve_account(config: &Config) -> Option<String> {
+	std::env::var("CLOUDFLARE_API_TOKEN").ok()?;
+
+	if let Some(zone) = &config.default_zone {
+		if let Some(info) = resolve(zone) {
+			return Some(info.account_id);
+		}
+	}
+
+	let cache = load_cache()?;
+	let mut account_ids = cache.values().map(|info| &info.account_id);
+	let first = account_ids.next()?;
+
+	account_ids.all(|id| id == first).then(|| first.clone())
+}
+
+fn fetch(token: &str, zone: &str) -> Option<ZoneInfo> {
+	let response: serde_json::Value =
+		ureq::get("https://api.cloudflare.com/client/v4/zones")
+			.query("name", zone)
+			.set("Authorization", &format!("Bearer {token}"))
+			.timeout(Duration::from_secs(10))
+			.call()
+			.ok()?
+			.into_json()
+			.ok()?;
+
+	let result = response.get("result")?.get(0)?;
+	Some(ZoneInfo {
+		zone_id: result.get("id")?.as_str()?.to_string(),
+		account_id: result.get("account")?.get("id")?.as_str()?.to_string(),
+	})
+}
+
+fn cache_path() -> Option<std::path::PathBuf> {
+	Some(Config::dir()?.join("zones.json"))
+}
+
+fn load_cache() -> Option<Cache> {
+	let contents = std::fs::read_to_string(cache_path()?).ok()?;
+	serde_json::from_str(&contents).ok()
+}
+
+fn save_cache(cache: &Cache) {
+	let Some(path) = cache_path() else { return };
+	if let Some(parent) = path.parent() {
+		let _ = std::fs::create_dir_all(parent);
+	}
+	if let Ok(json) = serde_json::to_string_pretty(cache) {
+		let _ = std::fs::write(path, json);
+	}
+}
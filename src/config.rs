@@ -0,0 +1,63 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+const DEFAULT_BASE_URL: &str = "https://dash.cloudflare.com";
+
+/// Settings read from `~/.config/cfurl/config.toml`, with `CFURL_*` env vars
+/// taking precedence over whatever the file contains.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+	pub account: Option<String>,
+	base_url: Option<String>,
+	pub default_zone: Option<String>,
+}
+
+impl Config {
+	/// Load the config file (if any) and apply `CFURL_ACCOUNT`,
+	/// `CFURL_BASE_URL` and `CFURL_DEFAULT_ZONE` overrides on top of it.
+	pub fn load() -> Self {
+		let mut config = Self::from_file().unwrap_or_default();
+
+		if let Ok(account) = std::env::var("CFURL_ACCOUNT") {
+			config.account = Some(account);
+		}
+		if let Ok(base_url) = std::env::var("CFURL_BASE_URL") {
+			config.base_url = Some(base_url);
+		}
+		if let Ok(zone) = std::env::var("CFURL_DEFAULT_ZONE") {
+			config.default_zone = Some(zone);
+		}
+
+		config
+	}
+
+	pub fn base_url(&self) -> &str {
+		self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+	}
+
+	/// The `:account` token to splice into dashboard URLs: the configured
+	/// account ID when we have one, otherwise the dashboard's own redirect
+	/// placeholder.
+	pub fn account_token(&self) -> &str {
+		self.account.as_deref().unwrap_or(":account")
+	}
+
+	/// Directory config and cache files live under, e.g.
+	/// `~/.config/cfurl`.
+	pub fn dir() -> Option<PathBuf> {
+		Some(dirs::config_dir()?.join("cfurl"))
+	}
+
+	fn from_file() -> Option<Self> {
+		let contents = std::fs::read_to_string(Self::dir()?.join("config.toml")).ok()?;
+
+		match toml::from_str(&contents) {
+			Ok(config) => Some(config),
+			Err(e) => {
+				eprintln!("✗ Failed to parse config.toml: {e}");
+				None
+			},
+		}
+	}
+}
@@ -2,10 +2,12 @@
 #![doc = include_str!("../README.md")]
 
 use clap::{Parser, Subcommand};
+use config::Config;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::time::Duration;
 
-const DASH_BASE: &str = "https://dash.cloudflare.com";
+mod cloudflare;
+mod config;
 
 #[derive(Parser)]
 #[command(name = "cfurl")]
@@ -14,14 +16,23 @@ const DASH_BASE: &str = "https://dash.cloudflare.com";
 struct Cli {
 	#[command(subcommand)]
 	command: Commands,
+
+	/// Print the resolved URL instead of opening it
+	#[arg(long, global = true, conflicts_with = "copy")]
+	print: bool,
+
+	/// Copy the resolved URL to the clipboard instead of opening it
+	#[arg(long, global = true, conflicts_with = "print")]
+	copy: bool,
 }
 
 #[derive(Subcommand)]
 enum Commands {
 	/// Open DNS settings for a zone
 	Dns {
-		/// Zone/domain name (e.g., miguel.build)
-		zone: String,
+		/// Zone/domain name (e.g., miguel.build). Falls back to the
+		/// configured default zone when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open Workers & Pages dashboard
@@ -56,14 +67,16 @@ enum Commands {
 
 	/// Open zone analytics
 	Analytics {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open security settings (WAF, etc.)
 	Security {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 		/// Specific section: waf, events, ddos, bots
 		#[arg(short, long)]
 		section: Option<String>,
@@ -71,56 +84,65 @@ enum Commands {
 
 	/// Open SSL/TLS settings
 	Ssl {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open caching settings
 	Caching {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open rules settings (redirects, transforms, etc.)
 	Rules {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open speed/optimization settings
 	Speed {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open email routing settings
 	Email {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open Spectrum settings
 	Spectrum {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open network settings
 	Network {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open traffic settings (load balancing, health checks)
 	Traffic {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open scrape shield settings
 	Scrape {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open Zero Trust dashboard
@@ -178,8 +200,9 @@ enum Commands {
 
 	/// Open Zaraz
 	Zaraz {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open Web Analytics
@@ -194,8 +217,9 @@ enum Commands {
 
 	/// Open zone overview
 	Zone {
-		/// Zone/domain name
-		zone: String,
+		/// Zone/domain name. Falls back to the configured default zone
+		/// when omitted.
+		zone: Option<String>,
 	},
 
 	/// Open the main dashboard
@@ -205,10 +229,11 @@ enum Commands {
 
 fn main() {
 	let cli = Cli::parse();
+	let config = Config::load();
 
 	let url = match cli.command {
-		Commands::Dns { zone } => zone_url(&zone, "dns"),
-		Commands::Analytics { zone } => zone_url(&zone, "analytics"),
+		Commands::Dns { zone } => zone_url(&config, zone, "dns"),
+		Commands::Analytics { zone } => zone_url(&config, zone, "analytics"),
 		Commands::Security { zone, section } => {
 			let path = match section.as_deref() {
 				Some("waf") => "security/waf",
@@ -217,61 +242,75 @@ fn main() {
 				Some("bots") => "security/bots",
 				_ => "security",
 			};
-			zone_url(&zone, path)
+			zone_url(&config, zone, path)
 		},
-		Commands::Ssl { zone } => zone_url(&zone, "ssl-tls"),
-		Commands::Caching { zone } => zone_url(&zone, "caching"),
-		Commands::Rules { zone } => zone_url(&zone, "rules"),
-		Commands::Speed { zone } => zone_url(&zone, "speed"),
-		Commands::Email { zone } => zone_url(&zone, "email"),
-		Commands::Spectrum { zone } => zone_url(&zone, "spectrum"),
-		Commands::Network { zone } => zone_url(&zone, "network"),
-		Commands::Traffic { zone } => zone_url(&zone, "traffic"),
-		Commands::Scrape { zone } => zone_url(&zone, "content-protection"),
-		Commands::Zaraz { zone } => zone_url(&zone, "zaraz"),
-		Commands::Zone { zone } => zone_url(&zone, ""),
-		Commands::Logs { zone: Some(zone) } => zone_url(&zone, "analytics/logs"),
+		Commands::Ssl { zone } => zone_url(&config, zone, "ssl-tls"),
+		Commands::Caching { zone } => zone_url(&config, zone, "caching"),
+		Commands::Rules { zone } => zone_url(&config, zone, "rules"),
+		Commands::Speed { zone } => zone_url(&config, zone, "speed"),
+		Commands::Email { zone } => zone_url(&config, zone, "email"),
+		Commands::Spectrum { zone } => zone_url(&config, zone, "spectrum"),
+		Commands::Network { zone } => zone_url(&config, zone, "network"),
+		Commands::Traffic { zone } => zone_url(&config, zone, "traffic"),
+		Commands::Scrape { zone } => zone_url(&config, zone, "content-protection"),
+		Commands::Zaraz { zone } => zone_url(&config, zone, "zaraz"),
+		Commands::Zone { zone } => zone_url(&config, zone, ""),
+		Commands::Logs { zone: Some(zone) } => zone_url(&config, Some(zone), "analytics/logs"),
 
 		Commands::Workers { name } => name.map_or_else(
-			|| account_url("workers-and-pages"),
-			|n| account_url(&format!("workers/services/view/{n}")),
+			|| account_url(&config, "workers-and-pages"),
+			|n| account_url(&config, &format!("workers/services/view/{n}")),
 		),
 		Commands::Pages { name } => name.map_or_else(
-			|| account_url("workers-and-pages"),
-			|n| account_url(&format!("pages/view/{n}")),
+			|| account_url(&config, "workers-and-pages"),
+			|n| account_url(&config, &format!("pages/view/{n}")),
 		),
 		Commands::R2 { bucket } => bucket.map_or_else(
-			|| account_url("r2"),
-			|b| account_url(&format!("r2/default/buckets/{b}")),
+			|| account_url(&config, "r2"),
+			|b| account_url(&config, &format!("r2/default/buckets/{b}")),
 		),
 		Commands::D1 { database } => database.map_or_else(
-			|| account_url("workers/d1"),
-			|d| account_url(&format!("workers/d1/databases/{d}")),
+			|| account_url(&config, "workers/d1"),
+			|d| account_url(&config, &format!("workers/d1/databases/{d}")),
 		),
 		Commands::Kv { namespace } => namespace.map_or_else(
-			|| account_url("workers/kv"),
-			|n| account_url(&format!("workers/kv/namespaces/{n}")),
+			|| account_url(&config, "workers/kv"),
+			|n| account_url(&config, &format!("workers/kv/namespaces/{n}")),
 		),
-		Commands::ZeroTrust | Commands::Access => account_url("access"),
-		Commands::Tunnels => account_url("access/tunnels"),
-		Commands::Stream => account_url("stream"),
-		Commands::Images => account_url("images"),
-		Commands::Queues => account_url("queues"),
-		Commands::Ai => account_url("ai"),
-		Commands::Vectorize => account_url("vectorize"),
-		Commands::Hyperdrive => account_url("hyperdrive"),
-		Commands::DurableObjects => account_url("workers/durable-objects"),
-		Commands::Account => account_url(""),
-		Commands::Billing => account_url("billing"),
-		Commands::AuditLog => account_url("audit-log"),
-		Commands::ApiTokens => format!("{DASH_BASE}/profile/api-tokens"),
-		Commands::Registrar => account_url("domains"),
-		Commands::Turnstile => account_url("turnstile"),
-		Commands::WebAnalytics => account_url("web-analytics"),
-		Commands::Logs { zone: None } => account_url("logs"),
-		Commands::Dash => DASH_BASE.to_string(),
+		Commands::ZeroTrust | Commands::Access => account_url(&config, "access"),
+		Commands::Tunnels => account_url(&config, "access/tunnels"),
+		Commands::Stream => account_url(&config, "stream"),
+		Commands::Images => account_url(&config, "images"),
+		Commands::Queues => account_url(&config, "queues"),
+		Commands::Ai => account_url(&config, "ai"),
+		Commands::Vectorize => account_url(&config, "vectorize"),
+		Commands::Hyperdrive => account_url(&config, "hyperdrive"),
+		Commands::DurableObjects => account_url(&config, "workers/durable-objects"),
+		Commands::Account => account_url(&config, ""),
+		Commands::Billing => account_url(&config, "billing"),
+		Commands::AuditLog => account_url(&config, "audit-log"),
+		Commands::ApiTokens => format!("{}/profile/api-tokens", config.base_url()),
+		Commands::Registrar => account_url(&config, "domains"),
+		Commands::Turnstile => account_url(&config, "turnstile"),
+		Commands::WebAnalytics => account_url(&config, "web-analytics"),
+		Commands::Logs { zone: None } => account_url(&config, "logs"),
+		Commands::Dash => config.base_url().to_string(),
 	};
 
+	if cli.print {
+		println!("{url}");
+		return;
+	}
+
+	if cli.copy {
+		if let Err(e) = copy_to_clipboard(&url) {
+			eprintln!("✗ Failed to copy to clipboard: {e}");
+			std::process::exit(1);
+		}
+		println!("✓ Copied to clipboard");
+		return;
+	}
+
 	let spinner = ProgressBar::new_spinner();
 	spinner.set_style(
 		ProgressStyle::default_spinner()
@@ -293,18 +332,44 @@ fn main() {
 	println!("✓ Opened");
 }
 
-fn zone_url(zone: &str, path: &str) -> String {
+fn zone_url(config: &Config, zone: Option<String>, path: &str) -> String {
+	let base = config.base_url();
+	let zone = resolve_zone(zone, config);
+
+	// With an API token we can resolve the real zone/account IDs and link
+	// straight to the page; otherwise fall back to the redirect URL.
+	let (account, zone) = match cloudflare::resolve(&zone) {
+		Some(info) => (info.account_id, info.zone_id),
+		None => (config.account_token().to_string(), zone),
+	};
+
 	if path.is_empty() {
-		format!("{DASH_BASE}/?to=/:account/{zone}")
+		format!("{base}/?to=/{account}/{zone}")
 	} else {
-		format!("{DASH_BASE}/?to=/:account/{zone}/{path}")
+		format!("{base}/?to=/{account}/{zone}/{path}")
 	}
 }
 
-fn account_url(path: &str) -> String {
+fn account_url(config: &Config, path: &str) -> String {
+	let base = config.base_url();
+	let account = cloudflare::resolve_account(config).unwrap_or_else(|| config.account_token().to_string());
+
 	if path.is_empty() {
-		format!("{DASH_BASE}/?to=/:account")
+		format!("{base}/?to=/{account}")
 	} else {
-		format!("{DASH_BASE}/?to=/:account/{path}")
+		format!("{base}/?to=/{account}/{path}")
 	}
 }
+
+/// Resolve a zone argument against the configured default zone, exiting
+/// with an error if neither is present.
+fn resolve_zone(zone: Option<String>, config: &Config) -> String {
+	zone.or_else(|| config.default_zone.clone()).unwrap_or_else(|| {
+		eprintln!("✗ No zone given and no default zone configured (set CFURL_DEFAULT_ZONE or default_zone in config.toml)");
+		std::process::exit(1);
+	})
+}
+
+fn copy_to_clipboard(url: &str) -> Result<(), arboard::Error> {
+	arboard::Clipboard::new()?.set_text(url)
+}